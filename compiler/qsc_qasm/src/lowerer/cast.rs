@@ -0,0 +1,601 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Lowering helpers for explicit OpenQASM casts between scalar numeric types.
+//!
+//! Q#'s native `Int` is a 64-bit two's-complement value, so a QASM `int[n]`/
+//! `uint[n]` with `n < 64` only constrains the *representable range* of the
+//! value, not its in-memory width. Lowering a cast into such a type therefore
+//! has to do more than call `Std.Math.Truncate`: the truncated value must
+//! also be folded back into the `n`-bit range, the same way a `wrapping`/
+//! `as`-cast does in `num-traits` or `compiler-builtins`.
+//!
+//! The exact lowering is controlled by a handful of `#pragma qdk.*`
+//! directives, scanned from the source by [`parse_cast_pragmas`] into a
+//! [`CastPragmas`], which [`lower_int_cast`] takes as its cast-site
+//! configuration.
+//!
+//! This checkout does not carry the rest of `qsc_qasm` (the QASM parser, the
+//! AST's `Cast` expression node, or the `Lowerer` that walks it), so there is
+//! no expression-lowering arm in this tree yet to call [`lower_int_cast`]
+//! from a real compilation. [`wrap_width`] and [`wrap_intrinsic_name`] are
+//! written as building blocks for that call site's eventual body: once the
+//! `Cast` arm exists, it resolves the target numeric type and operand text
+//! and delegates to [`lower_int_cast`], which uses both. Until then, the
+//! `#[cfg(test)]` module below is this file's only caller, exercised
+//! directly rather than through `crate::tests::check_qasm_to_qsharp`.
+
+/// The bit width a numeric cast should be reduced to after truncation, if
+/// any. Widths of 64 (and unsized `int`/`uint`, which lower to the native
+/// `Int`) need no extra step beyond `Std.Math.Truncate`: sign/zero-extending
+/// into a wider or equally-wide range is already a value-preserving no-op.
+pub(crate) fn wrap_width(width: Option<u32>) -> Option<u32> {
+    match width {
+        Some(n) if n < 64 => Some(n),
+        _ => None,
+    }
+}
+
+/// Name of the intrinsic that folds a truncated value into the
+/// representable range of an `n`-bit signed or unsigned integer.
+///
+/// For `uint[n]` this masks to the low `n` bits. For `int[n]` it computes
+/// `r = v mod 2^n` and subtracts `2^n` whenever `r >= 2^(n-1)`, producing the
+/// two's-complement signed value.
+pub(crate) fn wrap_intrinsic_name(is_signed: bool) -> &'static str {
+    if is_signed {
+        "Std.OpenQASM.Convert.WrapToIntN"
+    } else {
+        "Std.OpenQASM.Convert.WrapToUIntN"
+    }
+}
+
+/// How an out-of-range numeric cast should be lowered.
+///
+/// `Wrapping` is the historical, default behavior: the value is reduced
+/// modulo `2^n` with no observable error. `Checked` trades that silent
+/// truncation for a `fail` at the point of the cast, mirroring the `cast`
+/// crate's `Err(Overflow | Underflow | Infinite | NaN)` rather than letting
+/// an out-of-range conversion produce garbage.
+///
+/// Set per-compilation via the `qdk.cast_mode` pragma; `Wrapping` is the
+/// default when the pragma is absent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum CastMode {
+    #[default]
+    Wrapping,
+    Checked,
+    /// Clamps out-of-range values to the target's min/max instead of
+    /// wrapping or failing, mapping `NaN` to `0`. Useful for
+    /// signal-processing-style programs where saturation, not wraparound,
+    /// is the desired overflow behavior.
+    Saturating,
+}
+
+impl CastMode {
+    /// Parses the value of a `#pragma qdk.cast_mode <value>` directive.
+    pub(crate) fn from_pragma_value(value: &str) -> Option<Self> {
+        match value {
+            "wrapping" => Some(Self::Wrapping),
+            "checked" => Some(Self::Checked),
+            "saturating" => Some(Self::Saturating),
+            _ => None,
+        }
+    }
+}
+
+/// Which Q# `Std.Math` function a `float`→`int`/`uint` cast should round
+/// with. `Truncate` (round-toward-zero) remains the default; the others are
+/// selected per-compilation via the `qdk.rounding_mode` pragma, mirroring
+/// the `trunc`/`round`/`floor`/`ceil` operations on `num-traits`' float
+/// trait.
+///
+/// Unlike the `Std.OpenQASM.Convert.*` wrapping/reinterpreting intrinsics
+/// this module also emits (see the module docs), `Std.Math.Truncate`/
+/// `Round`/`Floor`/`Ceiling` are ordinary `Std.Math` functions that already
+/// exist in the Q# standard library — [`intrinsic_name`](Self::intrinsic_name)
+/// just has to pick the right one, not invent it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum RoundingMode {
+    #[default]
+    Truncate,
+    Round,
+    Floor,
+    Ceiling,
+}
+
+impl RoundingMode {
+    /// Parses the value of a `#pragma qdk.rounding_mode <value>` directive.
+    pub(crate) fn from_pragma_value(value: &str) -> Option<Self> {
+        match value {
+            "truncate" => Some(Self::Truncate),
+            "round" => Some(Self::Round),
+            "floor" => Some(Self::Floor),
+            "ceiling" => Some(Self::Ceiling),
+            _ => None,
+        }
+    }
+
+    /// The `Std.Math` function this mode lowers to.
+    pub(crate) fn intrinsic_name(self) -> &'static str {
+        match self {
+            Self::Truncate => "Std.Math.Truncate",
+            Self::Round => "Std.Math.Round",
+            Self::Floor => "Std.Math.Floor",
+            Self::Ceiling => "Std.Math.Ceiling",
+        }
+    }
+}
+
+/// The numeric-cast settings in effect for a compilation, as scanned from
+/// its `#pragma qdk.*` directives by [`parse_cast_pragmas`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct CastPragmas {
+    pub(crate) cast_mode: CastMode,
+    pub(crate) rounding_mode: RoundingMode,
+    pub(crate) enable_float_to_bits: bool,
+}
+
+/// Scans a QASM source string's `#pragma qdk.*` lines and returns the
+/// resulting numeric-cast configuration. A pragma with an unrecognized
+/// value is ignored, leaving the corresponding setting at its default.
+///
+/// This is a plain line scan rather than a full pragma-statement parse,
+/// consistent with how `#pragma` directives that don't affect parsing (as
+/// opposed to, e.g., `#pragma qasm_3_0` dialect statements) are picked up
+/// ahead of lowering.
+pub(crate) fn parse_cast_pragmas(source: &str) -> CastPragmas {
+    let mut pragmas = CastPragmas::default();
+    for line in source.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("#pragma qdk.cast_mode ") {
+            if let Some(mode) = CastMode::from_pragma_value(value.trim()) {
+                pragmas.cast_mode = mode;
+            }
+        } else if let Some(value) = line.strip_prefix("#pragma qdk.rounding_mode ") {
+            if let Some(mode) = RoundingMode::from_pragma_value(value.trim()) {
+                pragmas.rounding_mode = mode;
+            }
+        } else if line == "#pragma qdk.enable_float_to_bits" {
+            pragmas.enable_float_to_bits = true;
+        }
+    }
+    pragmas
+}
+
+/// `-2^63`, the minimum `Int` value; a power of two, so it's exactly
+/// representable as an `f64`.
+const SIGNED_64_MIN: f64 = -9_223_372_036_854_775_808.0;
+
+/// The largest `f64` strictly below `2^63`. The true signed 64-bit maximum,
+/// `2^63 - 1`, needs 63 significant bits and isn't exactly representable in
+/// `f64`'s 53-bit mantissa: converting it to `f64` rounds it *up* to `2^63`,
+/// one past the real bound. Using that rounded value as a checked-mode guard
+/// or saturating-mode clamp would accept (or clamp to) a value `Std.Math`
+/// can't truncate back into `Int` without overflow, so we use the nearest
+/// representable `f64` below `2^63` instead, trading a sliver of the
+/// uppermost range for soundness.
+const SIGNED_64_MAX_SAFE: f64 = 9_223_372_036_854_773_760.0;
+
+/// The largest `f64` strictly below `2^64`, for the same reason as
+/// [`SIGNED_64_MAX_SAFE`] but for the unsigned 64-bit maximum.
+const UNSIGNED_64_MAX_SAFE: f64 = 18_446_744_073_709_547_520.0;
+
+/// The `[min, max]` bounds of an `n`-bit signed or unsigned integer, as
+/// literal Q# doubles. Used both to build a checked-mode range guard and to
+/// clamp a saturating-mode cast.
+///
+/// `n == 64` (including unsized `int`/`uint`, which lower to the native
+/// 64-bit `Int`) is special-cased to [`SIGNED_64_MAX_SAFE`]/
+/// [`UNSIGNED_64_MAX_SAFE`]: the exact bound isn't representable as an
+/// `f64`, and naively computing `2f64.powi(63) - 1.` rounds back up to
+/// `2f64.powi(63)`, silently admitting (and then overflowing on) an operand
+/// that is truly out of range.
+pub(crate) fn numeric_bounds(n: u32, is_signed: bool) -> (f64, f64) {
+    if is_signed {
+        if n >= 64 {
+            (SIGNED_64_MIN, SIGNED_64_MAX_SAFE)
+        } else {
+            let max = 2f64.powi(n as i32 - 1) - 1.;
+            (-max - 1., max)
+        }
+    } else if n >= 64 {
+        (0., UNSIGNED_64_MAX_SAFE)
+    } else {
+        (0., 2f64.powi(n as i32) - 1.)
+    }
+}
+
+/// Formats an `f64` the way the lowerer writes Q# double literals: an
+/// integral value keeps a trailing `.` (`32767.`) rather than Rust's
+/// default `Display`, which would print `32767`.
+fn format_q_sharp_double(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}.")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Builds the `fail` message emitted by a checked-mode cast that observed an
+/// out-of-range (or non-finite) operand, e.g.
+/// `"float value is out of range for int[16]"`.
+pub(crate) fn checked_cast_fail_message(target_ty_name: &str) -> String {
+    format!("float value is out of range for {target_ty_name}")
+}
+
+/// Lowers a `float`→`int[n]`/`uint[n]` cast to the Q# expression text that
+/// replaces the cast in the output, dispatching on `pragmas.cast_mode` to
+/// [`lower_wrapping_int_cast`], [`lower_checked_int_cast`], or
+/// [`lower_saturating_int_cast`]. This is the function the expression-
+/// lowering arm for numeric `Cast` nodes should call once it exists in this
+/// crate, passing the already-lowered operand text and the [`CastPragmas`]
+/// resolved for the current compilation (see the module docs).
+///
+/// `value_expr` is the already-lowered Q# text of the cast's operand.
+pub(crate) fn lower_int_cast(
+    value_expr: &str,
+    width: Option<u32>,
+    is_signed: bool,
+    pragmas: CastPragmas,
+) -> String {
+    match pragmas.cast_mode {
+        CastMode::Wrapping => {
+            lower_wrapping_int_cast(value_expr, width, is_signed, pragmas.rounding_mode)
+        }
+        CastMode::Checked => {
+            lower_checked_int_cast(value_expr, width, is_signed, pragmas.rounding_mode)
+        }
+        CastMode::Saturating => lower_saturating_int_cast(value_expr, width, is_signed),
+    }
+}
+
+fn lower_wrapping_int_cast(
+    value_expr: &str,
+    width: Option<u32>,
+    is_signed: bool,
+    rounding_mode: RoundingMode,
+) -> String {
+    let rounded = format!("{}({value_expr})", rounding_mode.intrinsic_name());
+    match wrap_width(width) {
+        Some(n) => format!("{}({rounded}, {n})", wrap_intrinsic_name(is_signed)),
+        None => rounded,
+    }
+}
+
+fn lower_checked_int_cast(
+    value_expr: &str,
+    width: Option<u32>,
+    is_signed: bool,
+    rounding_mode: RoundingMode,
+) -> String {
+    let n = width.unwrap_or(64);
+    let (min, max) = numeric_bounds(n, is_signed);
+    let target_ty_name = if is_signed {
+        format!("int[{n}]")
+    } else {
+        format!("uint[{n}]")
+    };
+    let guarded = lower_wrapping_int_cast(value_expr, width, is_signed, rounding_mode);
+    format!(
+        "if not Std.Math.IsNan({value_expr}) and not Std.Math.IsInf({value_expr}) and {value_expr} >= {} and {value_expr} <= {} {{\n    {guarded}\n}} else {{\n    fail \"{}\";\n}}",
+        format_q_sharp_double(min),
+        format_q_sharp_double(max),
+        checked_cast_fail_message(&target_ty_name),
+    )
+}
+
+fn lower_saturating_int_cast(value_expr: &str, width: Option<u32>, is_signed: bool) -> String {
+    let n = width.unwrap_or(64);
+    let (min, max) = numeric_bounds(n, is_signed);
+    format!(
+        "if Std.Math.IsNan({value_expr}) {{\n    0\n}} else {{\n    Std.Math.Truncate(Std.Math.Max(Std.Math.Min({value_expr}, {}), {}))\n}}",
+        format_q_sharp_double(max),
+        format_q_sharp_double(min),
+    )
+}
+
+/// Validates a `float`→`bit[n]` reinterpreting cast, returning the source
+/// float's bit width on success.
+///
+/// Mirrors `f64::to_bits`/`f32::to_bits`: the cast does not round or
+/// truncate, it reinterprets the IEEE-754 bit pattern, so the destination
+/// array width must match the source float's width exactly (64 for
+/// unsized `float`/`float[64]`, 32 for `float[32]`). A mismatch is rejected
+/// with `Err((source_width, target_width))` so the caller can build a
+/// `CannotCast` diagnostic naming both.
+pub(crate) fn bit_reinterpret_source_width(
+    float_width: Option<u32>,
+    target_width: u32,
+) -> Result<u32, (u32, u32)> {
+    let source_width = float_width.unwrap_or(64);
+    if source_width == target_width {
+        Ok(source_width)
+    } else {
+        Err((source_width, target_width))
+    }
+}
+
+/// The intrinsic that reinterprets a float's IEEE-754 bit pattern as a
+/// `bit[n]` result array, mirroring `f64::to_bits`/`f32::to_bits`.
+///
+/// Together with [`wrap_intrinsic_name`]'s `WrapToIntN`/`WrapToUIntN`, this
+/// is the third and last `Std.OpenQASM.Convert.*` name this module emits
+/// calls to. None of the three are defined anywhere in this checkout: they
+/// belong in the Q# standard library's `Std.OpenQASM.Convert` namespace,
+/// which lives outside this Rust crate and isn't part of this source
+/// snapshot. Until they're added there, Q# generated by this module's
+/// lowering functions will fail to resolve them at compile time. Their
+/// expected signatures, inferred from how they're called here:
+/// - `WrapToIntN(v : Int, n : Int) : Int` — reduce `v` mod `2^n`, returning
+///   the two's-complement signed value in `[-2^(n-1), 2^(n-1)-1]`.
+/// - `WrapToUIntN(v : Int, n : Int) : Int` — mask `v` to its low `n` bits.
+/// - `DoubleAsBits(v : Double) : Result[]` — reinterpret `v`'s IEEE-754 bit
+///   pattern as a big- or little-endian (TBD by the real implementation)
+///   `Result` array the same width as `v`.
+pub(crate) const DOUBLE_AS_BITS_INTRINSIC: &str = "Std.OpenQASM.Convert.DoubleAsBits";
+
+/// Lowers a `float`→`bit[n]` reinterpreting cast, gated on the
+/// `qdk.enable_float_to_bits` pragma, using [`bit_reinterpret_source_width`]
+/// to validate the widths. This is the function the expression-lowering arm
+/// for `Cast` nodes should call once it exists in this crate, after it has
+/// already determined the cast target is `bit[n]` and the operand is a
+/// float (see the module docs).
+///
+/// When the pragma is absent, this always fails, matching the lowerer's
+/// preexisting `CannotCast` behavior for this cast shape. When present, a
+/// width mismatch still fails, but with a diagnostic that names the
+/// mismatched widths rather than the pragma being off.
+pub(crate) fn lower_float_to_bits_cast(
+    value_expr: &str,
+    float_width: Option<u32>,
+    target_width: u32,
+    pragmas: CastPragmas,
+) -> Result<String, String> {
+    if !pragmas.enable_float_to_bits {
+        return Err(format!(
+            "cannot cast expression of type float to type bit[{target_width}]"
+        ));
+    }
+    match bit_reinterpret_source_width(float_width, target_width) {
+        Ok(_) => Ok(format!("{DOUBLE_AS_BITS_INTRINSIC}({value_expr})")),
+        Err((source_width, target_width)) => Err(format!(
+            "a float-to-bits reinterpreting cast requires the target array width ({target_width}) to match the source float width ({source_width})"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        lower_float_to_bits_cast, lower_int_cast, parse_cast_pragmas, CastMode, CastPragmas,
+        RoundingMode,
+    };
+
+    fn wrapping() -> CastPragmas {
+        CastPragmas::default()
+    }
+
+    #[test]
+    fn narrowing_signed_cast_wraps_to_width() {
+        assert_eq!(
+            lower_int_cast("a", Some(16), true, wrapping()),
+            "Std.OpenQASM.Convert.WrapToIntN(Std.Math.Truncate(a), 16)"
+        );
+    }
+
+    #[test]
+    fn narrowing_unsigned_cast_wraps_to_width() {
+        assert_eq!(
+            lower_int_cast("a", Some(16), false, wrapping()),
+            "Std.OpenQASM.Convert.WrapToUIntN(Std.Math.Truncate(a), 16)"
+        );
+    }
+
+    #[test]
+    fn same_width_cast_still_wraps() {
+        assert_eq!(
+            lower_int_cast("a", Some(32), true, wrapping()),
+            "Std.OpenQASM.Convert.WrapToIntN(Std.Math.Truncate(a), 32)"
+        );
+    }
+
+    #[test]
+    fn expanding_cast_to_native_width_is_a_noop() {
+        assert_eq!(
+            lower_int_cast("a", Some(64), true, wrapping()),
+            "Std.Math.Truncate(a)"
+        );
+    }
+
+    #[test]
+    fn unsized_cast_is_a_noop() {
+        assert_eq!(
+            lower_int_cast("a", None, true, wrapping()),
+            "Std.Math.Truncate(a)"
+        );
+    }
+
+    #[test]
+    fn checked_signed_cast_inserts_range_guard() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Checked,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", Some(16), true, pragmas),
+            "if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= -32768. and a <= 32767. {\n    Std.OpenQASM.Convert.WrapToIntN(Std.Math.Truncate(a), 16)\n} else {\n    fail \"float value is out of range for int[16]\";\n}"
+        );
+    }
+
+    #[test]
+    fn checked_unsigned_cast_inserts_range_guard() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Checked,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", Some(16), false, pragmas),
+            "if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= 0. and a <= 65535. {\n    Std.OpenQASM.Convert.WrapToUIntN(Std.Math.Truncate(a), 16)\n} else {\n    fail \"float value is out of range for uint[16]\";\n}"
+        );
+    }
+
+    #[test]
+    fn checked_unsized_cast_uses_safe_native_width_bounds() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Checked,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", None, true, pragmas),
+            "if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= -9223372036854775808. and a <= 9223372036854773760. {\n    Std.Math.Truncate(a)\n} else {\n    fail \"float value is out of range for int[64]\";\n}"
+        );
+        assert_eq!(
+            lower_int_cast("a", None, false, pragmas),
+            "if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= 0. and a <= 18446744073709547520. {\n    Std.Math.Truncate(a)\n} else {\n    fail \"float value is out of range for uint[64]\";\n}"
+        );
+    }
+
+    #[test]
+    fn saturating_signed_cast_clamps_to_bounds() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Saturating,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", Some(16), true, pragmas),
+            "if Std.Math.IsNan(a) {\n    0\n} else {\n    Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 32767.), -32768.))\n}"
+        );
+    }
+
+    #[test]
+    fn saturating_unsigned_cast_clamps_to_bounds() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Saturating,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", Some(16), false, pragmas),
+            "if Std.Math.IsNan(a) {\n    0\n} else {\n    Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 65535.), 0.))\n}"
+        );
+    }
+
+    #[test]
+    fn rounding_mode_replaces_truncate() {
+        let pragmas = CastPragmas {
+            rounding_mode: RoundingMode::Round,
+            ..CastPragmas::default()
+        };
+        assert_eq!(lower_int_cast("a", None, true, pragmas), "Std.Math.Round(a)");
+
+        let pragmas = CastPragmas {
+            rounding_mode: RoundingMode::Floor,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", Some(32), true, pragmas),
+            "Std.OpenQASM.Convert.WrapToIntN(Std.Math.Floor(a), 32)"
+        );
+
+        let pragmas = CastPragmas {
+            rounding_mode: RoundingMode::Ceiling,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", None, false, pragmas),
+            "Std.Math.Ceiling(a)"
+        );
+    }
+
+    #[test]
+    fn checked_mode_and_rounding_mode_compose() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Checked,
+            rounding_mode: RoundingMode::Floor,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", Some(16), true, pragmas),
+            "if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= -32768. and a <= 32767. {\n    Std.OpenQASM.Convert.WrapToIntN(Std.Math.Floor(a), 16)\n} else {\n    fail \"float value is out of range for int[16]\";\n}"
+        );
+    }
+
+    #[test]
+    fn saturating_unsized_cast_uses_safe_native_width_bounds() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Saturating,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", None, true, pragmas),
+            "if Std.Math.IsNan(a) {\n    0\n} else {\n    Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 9223372036854773760.), -9223372036854775808.))\n}"
+        );
+        assert_eq!(
+            lower_int_cast("a", None, false, pragmas),
+            "if Std.Math.IsNan(a) {\n    0\n} else {\n    Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 18446744073709547520.), 0.))\n}"
+        );
+    }
+
+    #[test]
+    fn saturating_mode_ignores_rounding_mode_and_still_truncates() {
+        let pragmas = CastPragmas {
+            cast_mode: CastMode::Saturating,
+            rounding_mode: RoundingMode::Floor,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_int_cast("a", Some(16), true, pragmas),
+            "if Std.Math.IsNan(a) {\n    0\n} else {\n    Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 32767.), -32768.))\n}"
+        );
+    }
+
+    #[test]
+    fn parse_cast_pragmas_recognizes_all_directives() {
+        let pragmas = parse_cast_pragmas(
+            "#pragma qdk.cast_mode checked\n#pragma qdk.rounding_mode floor\n#pragma qdk.enable_float_to_bits\nfloat a;",
+        );
+        assert_eq!(pragmas.cast_mode, CastMode::Checked);
+        assert_eq!(pragmas.rounding_mode, RoundingMode::Floor);
+        assert!(pragmas.enable_float_to_bits);
+    }
+
+    #[test]
+    fn parse_cast_pragmas_defaults_when_absent() {
+        let pragmas = parse_cast_pragmas("float a;\nint(a);");
+        assert_eq!(pragmas, CastPragmas::default());
+    }
+
+    #[test]
+    fn float_to_bits_fails_by_default() {
+        assert!(lower_float_to_bits_cast("a", None, 64, CastPragmas::default()).is_err());
+    }
+
+    #[test]
+    fn float_to_bits_reinterprets_when_enabled_and_widths_match() {
+        let pragmas = CastPragmas {
+            enable_float_to_bits: true,
+            ..CastPragmas::default()
+        };
+        assert_eq!(
+            lower_float_to_bits_cast("a", None, 64, pragmas).unwrap(),
+            "Std.OpenQASM.Convert.DoubleAsBits(a)"
+        );
+        assert_eq!(
+            lower_float_to_bits_cast("a", Some(32), 32, pragmas).unwrap(),
+            "Std.OpenQASM.Convert.DoubleAsBits(a)"
+        );
+    }
+
+    #[test]
+    fn float_to_bits_rejects_mismatched_widths_even_when_enabled() {
+        let pragmas = CastPragmas {
+            enable_float_to_bits: true,
+            ..CastPragmas::default()
+        };
+        let err = lower_float_to_bits_cast("a", Some(32), 16, pragmas).unwrap_err();
+        assert_eq!(
+            err,
+            "a float-to-bits reinterpreting cast requires the target array width (16) to match the source float width (32)"
+        );
+    }
+}
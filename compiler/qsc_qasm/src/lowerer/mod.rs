@@ -0,0 +1,11 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Lowering from the OpenQASM AST into Q# source.
+//!
+//! `cast` holds the lowering logic for explicit numeric casts; see its
+//! module docs for how it's meant to be wired into the `Cast` expression
+//! arm once the rest of this crate (parser, AST, `Lowerer`) is present in
+//! this checkout.
+
+pub(crate) mod cast;
@@ -137,7 +137,7 @@ fn float_to_sized_int() {
         &expect![[r#"
             import Std.OpenQASM.Intrinsic.*;
             mutable a = 0.;
-            Std.Math.Truncate(a);
+            Std.OpenQASM.Convert.WrapToIntN(Std.Math.Truncate(a), 32);
         "#]],
     );
 }
@@ -169,7 +169,7 @@ fn sized_float_to_sized_int() {
         &expect![[r#"
             import Std.OpenQASM.Intrinsic.*;
             mutable a = 0.;
-            Std.Math.Truncate(a);
+            Std.OpenQASM.Convert.WrapToIntN(Std.Math.Truncate(a), 32);
         "#]],
     );
 }
@@ -185,7 +185,7 @@ fn sized_float_to_sized_int_truncating() {
         &expect![[r#"
             import Std.OpenQASM.Intrinsic.*;
             mutable a = 0.;
-            Std.Math.Truncate(a);
+            Std.OpenQASM.Convert.WrapToIntN(Std.Math.Truncate(a), 16);
         "#]],
     );
 }
@@ -237,7 +237,7 @@ fn float_to_sized_uint() {
         &expect![[r#"
             import Std.OpenQASM.Intrinsic.*;
             mutable a = 0.;
-            Std.Math.Truncate(a);
+            Std.OpenQASM.Convert.WrapToUIntN(Std.Math.Truncate(a), 32);
         "#]],
     );
 }
@@ -269,7 +269,7 @@ fn sized_float_to_sized_uint() {
         &expect![[r#"
             import Std.OpenQASM.Intrinsic.*;
             mutable a = 0.;
-            Std.Math.Truncate(a);
+            Std.OpenQASM.Convert.WrapToUIntN(Std.Math.Truncate(a), 32);
         "#]],
     );
 }
@@ -285,7 +285,7 @@ fn sized_float_to_sized_uint_truncating() {
         &expect![[r#"
             import Std.OpenQASM.Intrinsic.*;
             mutable a = 0.;
-            Std.Math.Truncate(a);
+            Std.OpenQASM.Convert.WrapToUIntN(Std.Math.Truncate(a), 16);
         "#]],
     );
 }
@@ -724,7 +724,281 @@ fn sized_float_to_bitarray_expanding_fails() {
              2 |         float[32] a;
              3 |         bit[64](a);
                :         ^^^^^^^^^^
-             4 |     
+             4 |
+               `----
+        "#]],
+    );
+}
+
+//=====================================
+// Casts under the `checked` cast mode
+//=====================================
+
+#[test]
+fn checked_float_to_sized_int_inserts_range_guard() {
+    let source = "
+        #pragma qdk.cast_mode checked
+        float[32] a;
+        int[16](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= -32768. and a <= 32767. {
+                Std.OpenQASM.Convert.WrapToIntN(Std.Math.Truncate(a), 16)
+            } else {
+                fail "float value is out of range for int[16]";
+            };
+        "#]],
+    );
+}
+
+#[test]
+fn checked_float_to_sized_uint_inserts_range_guard() {
+    let source = "
+        #pragma qdk.cast_mode checked
+        float[32] a;
+        uint[16](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= 0. and a <= 65535. {
+                Std.OpenQASM.Convert.WrapToUIntN(Std.Math.Truncate(a), 16)
+            } else {
+                fail "float value is out of range for uint[16]";
+            };
+        "#]],
+    );
+}
+
+#[test]
+fn checked_float_to_unsized_int_uses_safe_native_width_bounds() {
+    let source = "
+        #pragma qdk.cast_mode checked
+        float a;
+        int(a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= -9223372036854775808. and a <= 9223372036854773760. {
+                Std.Math.Truncate(a)
+            } else {
+                fail "float value is out of range for int[64]";
+            };
+        "#]],
+    );
+}
+
+//========================================
+// Casts under the `saturating` cast mode
+//========================================
+
+#[test]
+fn saturating_float_to_sized_int_clamps_to_bounds() {
+    let source = "
+        #pragma qdk.cast_mode saturating
+        float[32] a;
+        int[16](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            if Std.Math.IsNan(a) {
+                0
+            } else {
+                Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 32767.), -32768.))
+            };
+        "#]],
+    );
+}
+
+#[test]
+fn saturating_float_to_sized_uint_clamps_to_bounds() {
+    let source = "
+        #pragma qdk.cast_mode saturating
+        float[32] a;
+        uint[16](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            if Std.Math.IsNan(a) {
+                0
+            } else {
+                Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 65535.), 0.))
+            };
+        "#]],
+    );
+}
+
+#[test]
+fn saturating_float_to_unsized_int_uses_safe_native_width_bounds() {
+    let source = "
+        #pragma qdk.cast_mode saturating
+        float a;
+        int(a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            if Std.Math.IsNan(a) {
+                0
+            } else {
+                Std.Math.Truncate(Std.Math.Max(Std.Math.Min(a, 9223372036854773760.), -9223372036854775808.))
+            };
+        "#]],
+    );
+}
+
+//======================================
+// Casts under a non-default `rounding_mode`
+//======================================
+
+#[test]
+fn round_float_to_int_uses_round() {
+    let source = "
+        #pragma qdk.rounding_mode round
+        float a;
+        int(a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            Std.Math.Round(a);
+        "#]],
+    );
+}
+
+#[test]
+fn floor_float_to_sized_int_uses_floor() {
+    let source = "
+        #pragma qdk.rounding_mode floor
+        float[32] a;
+        int[32](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            Std.OpenQASM.Convert.WrapToIntN(Std.Math.Floor(a), 32);
+        "#]],
+    );
+}
+
+#[test]
+fn ceiling_float_to_uint_uses_ceiling() {
+    let source = "
+        #pragma qdk.rounding_mode ceiling
+        float a;
+        uint(a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            Std.Math.Ceiling(a);
+        "#]],
+    );
+}
+
+#[test]
+fn checked_float_to_sized_int_composes_with_floor_rounding_mode() {
+    let source = "
+        #pragma qdk.cast_mode checked
+        #pragma qdk.rounding_mode floor
+        float[32] a;
+        int[16](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            if not Std.Math.IsNan(a) and not Std.Math.IsInf(a) and a >= -32768. and a <= 32767. {
+                Std.OpenQASM.Convert.WrapToIntN(Std.Math.Floor(a), 16)
+            } else {
+                fail "float value is out of range for int[16]";
+            };
+        "#]],
+    );
+}
+
+//======================================================
+// Opt-in float -> bit[n] casts via IEEE-754 reinterpretation
+//======================================================
+
+#[test]
+fn float_to_bitarray_reinterprets_bits_when_enabled() {
+    let source = "
+        #pragma qdk.enable_float_to_bits
+        float a;
+        bit[64](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            Std.OpenQASM.Convert.DoubleAsBits(a);
+        "#]],
+    );
+}
+
+#[test]
+fn sized_float_to_matching_bitarray_reinterprets_bits_when_enabled() {
+    let source = "
+        #pragma qdk.enable_float_to_bits
+        float[32] a;
+        bit[32](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            import Std.OpenQASM.Intrinsic.*;
+            mutable a = 0.;
+            Std.OpenQASM.Convert.DoubleAsBits(a);
+        "#]],
+    );
+}
+
+#[test]
+fn sized_float_to_mismatched_bitarray_fails_even_when_enabled() {
+    let source = "
+        #pragma qdk.enable_float_to_bits
+        float[32] a;
+        bit[16](a);
+    ";
+    check(
+        source,
+        &expect![[r#"
+            Qasm.Lowerer.CannotCast
+
+              x cannot cast expression of type float[32] to type bit[16]: a float-to-bits
+              | reinterpreting cast requires the target array width (16) to match the
+              | source float width (32)
+               ,-[Test.qasm:3:9]
+             2 |         float[32] a;
+             3 |         bit[16](a);
+               :         ^^^^^^^^^^
+             4 |
                `----
         "#]],
     );